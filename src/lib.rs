@@ -1,10 +1,14 @@
+mod matchmaking;
 mod rating;
 mod score;
 mod simple_trueskill;
 mod trueskill;
 mod utils;
+mod weng_lin;
 
 pub use crate::trueskill::TrueSkill;
+pub use matchmaking::{balance, balance_annealed};
 pub use rating::Rating;
 pub use score::Score;
 pub use simple_trueskill::SimpleTrueSkill;
+pub use weng_lin::WengLin;
@@ -1,45 +1,213 @@
-use crate::{Rating, TrueSkill};
+use std::time::{Duration, Instant};
 
 use itertools::Itertools;
-use num_traits::Float;
-
-pub fn quality<F: Float>(env: &TrueSkill<F>, team1: &[Rating<F>], team2: &[Rating<F>]) -> F {
-    let player1: Rating<F> = team1.into();
-    let player2: Rating<F> = team2.into();
-    let beta = env.beta();
-    let n = team1.len() + team2.len();
-    let nb2 = F::from(n).unwrap() * beta * beta;
-    let sigma1 = player1.sigma();
-    let sigma2 = player2.sigma();
-    let dmu = player1.mu() - player2.mu();
-    let c2 = nb2 + sigma1 * sigma1 + sigma2 * sigma2;
-    let u = (nb2 / c2).sqrt();
-    let v = (-(dmu * dmu) / (F::from(2).unwrap() * c2)).exp();
-    u * v
-}
-
-pub fn balance<F: Float>(env: &TrueSkill<F>, players: &[Rating<F>]) -> (Vec<usize>, Vec<usize>) {
-    let mut best_quality = F::zero();
-    let mut best_teams = None;
+use rand::seq::SliceRandom;
+use rand::Rng;
+#[cfg(feature = "rayon")]
+use rayon::iter::{ParallelBridge, ParallelIterator};
+
+use crate::{Rating, TrueSkill};
+
+type Split = (Vec<usize>, Vec<usize>);
+type Best = Option<(f64, Split)>;
+
+fn split_teams(len: usize, team2_indices: Vec<usize>) -> Split {
+    let mut is_team1 = vec![true; len];
+    for i in team2_indices {
+        is_team1[i] = false;
+    }
+    let mut team1 = Vec::new();
+    let mut team2 = Vec::new();
+    for (i, &check) in is_team1.iter().enumerate() {
+        if check { &mut team1 } else { &mut team2 }.push(i);
+    }
+    (team1, team2)
+}
+
+fn split_quality(env: &TrueSkill, players: &[Rating], teams: &Split) -> f64 {
+    env.quality(
+        &teams.0.iter().map(|&x| players[x]).collect::<Vec<_>>(),
+        &teams.1.iter().map(|&x| players[x]).collect::<Vec<_>>(),
+    )
+}
+
+fn better(best: Best, candidate: (f64, Split)) -> Best {
+    match best {
+        Some((best_quality, _)) if best_quality >= candidate.0 => best,
+        _ => Some(candidate),
+    }
+}
+
+#[cfg(feature = "rayon")]
+fn merge_best(a: Best, b: Best) -> Best {
+    match b {
+        Some(candidate) => better(a, candidate),
+        None => a,
+    }
+}
+
+/// O(C(n, n/2)); becomes impractical past ~20 players. Use `balance_annealed`
+/// for larger lobbies or for more than two teams.
+#[cfg(not(feature = "rayon"))]
+pub fn balance(env: &TrueSkill, players: &[Rating]) -> (Vec<usize>, Vec<usize>) {
     let len = players.len();
-    for v in (1..len).combinations(len / 2) {
-        let mut is_team1 = vec![true; len];
-        for i in v {
-            is_team1[i] = false;
+    let best: Best = (1..len).combinations(len / 2).fold(None, |best, v| {
+        let teams = split_teams(len, v);
+        let quality = split_quality(env, players, &teams);
+        better(best, (quality, teams))
+    });
+    best.map(|(_, teams)| teams).unwrap_or((vec![], vec![]))
+}
+
+/// Same exact search as the serial path, but the combination loop is split
+/// across threads with rayon since each split's `quality` is independent.
+#[cfg(feature = "rayon")]
+pub fn balance(env: &TrueSkill, players: &[Rating]) -> (Vec<usize>, Vec<usize>) {
+    let len = players.len();
+    let best = (1..len)
+        .combinations(len / 2)
+        .par_bridge()
+        .fold(
+            || None,
+            |best, v| {
+                let teams = split_teams(len, v);
+                let quality = split_quality(env, players, &teams);
+                better(best, (quality, teams))
+            },
+        )
+        .reduce(|| None, merge_best);
+    best.map(|(_, teams)| teams).unwrap_or((vec![], vec![]))
+}
+
+fn total_quality(env: &TrueSkill, players: &[Rating], teams: &[Vec<usize>]) -> f64 {
+    let ratings = teams
+        .iter()
+        .map(|team| team.iter().map(|&i| players[i]).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut total = 0.0;
+    for (i, team_i) in ratings.iter().enumerate() {
+        for team_j in &ratings[i + 1..] {
+            total += env.quality(team_i, team_j);
+        }
+    }
+    total
+}
+
+/// Local search maximizing total pairwise `quality`, scaling to arbitrarily many
+/// teams and sizes. `team_sizes` gives each team's capacity; its sum must equal
+/// `players.len()`.
+pub fn balance_annealed(
+    env: &TrueSkill,
+    players: &[Rating],
+    team_sizes: &[usize],
+    time_limit: Duration,
+) -> Vec<Vec<usize>> {
+    assert_eq!(team_sizes.iter().sum::<usize>(), players.len());
+
+    let mut rng = rand::thread_rng();
+    let mut indices = (0..players.len()).collect::<Vec<_>>();
+    indices.shuffle(&mut rng);
+    let mut teams = Vec::with_capacity(team_sizes.len());
+    let mut offset = 0;
+    for &size in team_sizes {
+        teams.push(indices[offset..offset + size].to_vec());
+        offset += size;
+    }
+
+    let mut current_quality = total_quality(env, players, &teams);
+    let mut best_teams = teams.clone();
+    let mut best_quality = current_quality;
+
+    let start_temperature: f64 = 1.0;
+    let min_temperature: f64 = 1e-3;
+    let start = Instant::now();
+    while teams.len() > 1 && start.elapsed() < time_limit {
+        let fraction = start.elapsed().as_secs_f64() / time_limit.as_secs_f64();
+        let temperature = start_temperature * (min_temperature / start_temperature).powf(fraction);
+
+        let team_a = rng.gen_range(0..teams.len());
+        let team_b = rng.gen_range(0..teams.len());
+        if team_a == team_b || teams[team_a].is_empty() || teams[team_b].is_empty() {
+            continue;
         }
-        let mut team1 = Vec::new();
-        let mut team2 = Vec::new();
-        for (i, &check) in is_team1.iter().enumerate() {
-            if check { &mut team1 } else { &mut team2 }.push(i);
+        let player_a = rng.gen_range(0..teams[team_a].len());
+        let player_b = rng.gen_range(0..teams[team_b].len());
+
+        let mut candidate = teams.clone();
+        candidate[team_a][player_a] = teams[team_b][player_b];
+        candidate[team_b][player_b] = teams[team_a][player_a];
+
+        let candidate_quality = total_quality(env, players, &candidate);
+        let delta = candidate_quality - current_quality;
+        if delta > 0.0 || rng.gen::<f64>() < (delta / temperature).exp() {
+            teams = candidate;
+            current_quality = candidate_quality;
+            if current_quality > best_quality {
+                best_quality = current_quality;
+                best_teams = teams.clone();
+            }
         }
-        let quality = env.quality(
-            &team1.iter().map(|&x| players[x]).collect::<Vec<_>>(),
-            &team2.iter().map(|&x| players[x]).collect::<Vec<_>>(),
-        );
-        if quality > best_quality {
-            best_quality = quality;
-            best_teams = Some((team1, team2));
+    }
+
+    best_teams
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static ENV: TrueSkill = TrueSkill::new(25.0, 25.0 / 3.0, 25.0 / 6.0, 25.0 / 300.0, 0.1);
+
+    #[test]
+    fn balance_picks_the_most_even_split() {
+        let players = [
+            Rating::new(0.0, 0.5),
+            Rating::new(10.0, 0.5),
+            Rating::new(20.0, 0.5),
+            Rating::new(30.0, 0.5),
+        ];
+        let (team1, team2) = balance(&ENV, &players);
+        assert_eq!(team1, vec![0, 3]);
+        assert_eq!(team2, vec![1, 2]);
+    }
+
+    #[test]
+    fn balance_annealed_respects_team_sizes() {
+        let players = [
+            Rating::new(0.0, 0.5),
+            Rating::new(10.0, 0.5),
+            Rating::new(20.0, 0.5),
+            Rating::new(30.0, 0.5),
+            Rating::new(40.0, 0.5),
+        ];
+        let team_sizes = [2, 3];
+        let teams = balance_annealed(&ENV, &players, &team_sizes, Duration::from_millis(50));
+
+        assert_eq!(teams.len(), team_sizes.len());
+        for (team, &size) in teams.iter().zip(&team_sizes) {
+            assert_eq!(team.len(), size);
         }
+        let mut indices = teams.iter().flatten().copied().collect::<Vec<_>>();
+        indices.sort_unstable();
+        assert_eq!(indices, (0..players.len()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn balance_annealed_improves_on_a_lopsided_split() {
+        let players = (0..9)
+            .map(|i| Rating::new(i as f64 * 10.0, 0.5))
+            .collect::<Vec<_>>();
+        let team_sizes = [3, 3, 3];
+        let lopsided_teams = vec![
+            (0..3).collect::<Vec<_>>(),
+            (3..6).collect::<Vec<_>>(),
+            (6..9).collect::<Vec<_>>(),
+        ];
+        let lopsided_quality = total_quality(&ENV, &players, &lopsided_teams);
+
+        let teams = balance_annealed(&ENV, &players, &team_sizes, Duration::from_millis(200));
+        let quality = total_quality(&ENV, &players, &teams);
+
+        assert!(quality > lopsided_quality);
     }
-    best_teams.unwrap_or((vec![], vec![]))
 }
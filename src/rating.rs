@@ -29,6 +29,16 @@ impl Rating {
     pub const fn variance(&self) -> f64 {
         self.variance
     }
+
+    #[inline]
+    pub fn ordinal(&self, k: f64) -> f64 {
+        self.mean - k * self.variance.sqrt()
+    }
+
+    #[inline]
+    pub fn skill(&self, k: f64, min: i64, max: i64) -> i64 {
+        (self.ordinal(k).round() as i64).clamp(min, max)
+    }
 }
 
 macro_rules! impl_add {
@@ -122,6 +132,19 @@ mod test {
         assert_eq!(rating.variance(), VARIANCE + RATING2.variance());
     }
 
+    #[test]
+    fn ordinal() {
+        assert_eq!(RATING.ordinal(3.0), MEAN - 3.0 * VARIANCE.sqrt());
+        assert_eq!(RATING.ordinal(0.0), MEAN);
+    }
+
+    #[test]
+    fn skill() {
+        assert_eq!(RATING.skill(3.0, 0, 100), 1);
+        assert_eq!(RATING.skill(3.0, 3, 100), 3);
+        assert_eq!(Rating::new(-10.0, 0.0).skill(3.0, 0, 100), 0);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serialize() {
@@ -94,6 +94,16 @@ impl SimpleTrueSkill {
         u * v.exp()
     }
 
+    #[inline]
+    pub fn win_probability(&self, team1: &[Rating], team2: &[Rating]) -> f64 {
+        let player1 = Rating::from_iter(team1);
+        let player2 = Rating::from_iter(team2);
+        let n = team1.len() + team2.len();
+        let dmu = player1.mean() - player2.mean();
+        let c2 = (n as f64) * self.beta * self.beta + player1.variance() + player2.variance();
+        cdf(dmu / c2.sqrt())
+    }
+
     #[inline]
     fn vw(x: f64) -> [f64; 2] {
         let v = pdf(x) / cdf(x);
@@ -172,4 +182,12 @@ mod test {
         let quality = TRUESKILL.quality(&TEAM1, &TEAM2);
         assert_almost_eq!(quality, 0.5910630134064284, 1e-15);
     }
+
+    #[test]
+    fn win_probability() {
+        let win_probability = TRUESKILL.win_probability(&TEAM1, &TEAM2);
+        assert_almost_eq!(win_probability, 0.6217525388931737, 1e-15);
+        let win_probability = TRUESKILL.win_probability(&TEAM2, &TEAM1);
+        assert_almost_eq!(win_probability, 0.3782474611068263, 1e-15);
+    }
 }
@@ -88,6 +88,114 @@ impl TrueSkill {
         team2.iter_mut().for_each(|x| f(x, -1.0));
     }
 
+    #[inline]
+    pub fn update_multi(
+        &self,
+        teams: &mut [&mut [Rating]],
+        ranks: &[usize],
+        convergence_tolerance: f64,
+        max_iterations: usize,
+    ) {
+        assert_eq!(teams.len(), ranks.len());
+        let add_dynamic_factor = |x: &mut Rating| *x += Rating::new(0.0, self.tau * self.tau);
+        for team in teams.iter_mut() {
+            team.iter_mut().for_each(add_dynamic_factor);
+        }
+        let team_ratings = teams
+            .iter()
+            .map(|team| Rating::from_iter(team.iter()))
+            .collect::<Vec<_>>();
+        let team_sizes = teams.iter().map(|team| team.len()).collect::<Vec<_>>();
+        let mut order = (0..teams.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| ranks[i]);
+        let pairs = order
+            .windows(2)
+            .map(|window| (window[0], window[1]))
+            .collect::<Vec<_>>();
+
+        let prior_precision = team_ratings
+            .iter()
+            .map(|rating| 1.0 / rating.variance())
+            .collect::<Vec<_>>();
+        let mut precision = prior_precision.clone();
+        let mut precision_mean = team_ratings
+            .iter()
+            .zip(&prior_precision)
+            .map(|(rating, &p)| rating.mean() * p)
+            .collect::<Vec<_>>();
+        let mut message_precision = vec![[0.0, 0.0]; pairs.len()];
+        let mut message_precision_mean = vec![[0.0, 0.0]; pairs.len()];
+
+        for _ in 0..max_iterations {
+            let mut max_change = 0.0_f64;
+            for (k, &(hi, lo)) in pairs.iter().enumerate() {
+                let cavity_precision_hi = precision[hi] - message_precision[k][0];
+                let cavity_precision_mean_hi = precision_mean[hi] - message_precision_mean[k][0];
+                let cavity_precision_lo = precision[lo] - message_precision[k][1];
+                let cavity_precision_mean_lo = precision_mean[lo] - message_precision_mean[k][1];
+                let cavity_variance_hi = 1.0 / cavity_precision_hi;
+                let cavity_mean_hi = cavity_precision_mean_hi * cavity_variance_hi;
+                let cavity_variance_lo = 1.0 / cavity_precision_lo;
+                let cavity_mean_lo = cavity_precision_mean_lo * cavity_variance_lo;
+
+                let n = (team_sizes[hi] + team_sizes[lo]) as f64;
+                let c2 = n * self.beta * self.beta + cavity_variance_hi + cavity_variance_lo;
+                let c = c2.sqrt();
+                let t = (cavity_mean_hi - cavity_mean_lo) / c;
+                let epsilon = Self::draw_margin(self.draw_probability(), n, self.beta) / c;
+                let [v, w] = if ranks[hi] == ranks[lo] {
+                    Self::vw_draw(t, epsilon)
+                } else {
+                    Self::vw(t, epsilon)
+                };
+
+                let new_mean_hi = cavity_mean_hi + cavity_variance_hi / c * v;
+                let new_variance_hi = cavity_variance_hi * (1.0 - cavity_variance_hi / c2 * w);
+                let new_mean_lo = cavity_mean_lo - cavity_variance_lo / c * v;
+                let new_variance_lo = cavity_variance_lo * (1.0 - cavity_variance_lo / c2 * w);
+
+                let new_message_precision_hi = 1.0 / new_variance_hi - cavity_precision_hi;
+                let new_message_precision_mean_hi =
+                    new_mean_hi / new_variance_hi - cavity_precision_mean_hi;
+                let new_message_precision_lo = 1.0 / new_variance_lo - cavity_precision_lo;
+                let new_message_precision_mean_lo =
+                    new_mean_lo / new_variance_lo - cavity_precision_mean_lo;
+
+                let old_mean_hi = precision_mean[hi] / precision[hi];
+                let old_mean_lo = precision_mean[lo] / precision[lo];
+
+                precision[hi] += new_message_precision_hi - message_precision[k][0];
+                precision_mean[hi] += new_message_precision_mean_hi - message_precision_mean[k][0];
+                precision[lo] += new_message_precision_lo - message_precision[k][1];
+                precision_mean[lo] += new_message_precision_mean_lo - message_precision_mean[k][1];
+                message_precision[k] = [new_message_precision_hi, new_message_precision_lo];
+                message_precision_mean[k] =
+                    [new_message_precision_mean_hi, new_message_precision_mean_lo];
+
+                max_change = max_change
+                    .max((precision_mean[hi] / precision[hi] - old_mean_hi).abs())
+                    .max((precision_mean[lo] / precision[lo] - old_mean_lo).abs());
+            }
+            if max_change < convergence_tolerance {
+                break;
+            }
+        }
+
+        for (i, team) in teams.iter_mut().enumerate() {
+            let posterior_variance = 1.0 / precision[i];
+            let posterior_mean = precision_mean[i] * posterior_variance;
+            let v = (posterior_mean - team_ratings[i].mean()) / team_ratings[i].variance();
+            let w = (1.0 - posterior_variance / team_ratings[i].variance())
+                / team_ratings[i].variance();
+            for x in team.iter_mut() {
+                *x = Rating::new(
+                    x.mean() + x.variance() * v,
+                    x.variance() * (1.0 - x.variance() * w),
+                );
+            }
+        }
+    }
+
     #[inline]
     pub fn create_rating(&self) -> Rating {
         Rating::new(self.mu, self.sigma * self.sigma)
@@ -106,6 +214,16 @@ impl TrueSkill {
         u * v.exp()
     }
 
+    #[inline]
+    pub fn win_probability(&self, team1: &[Rating], team2: &[Rating]) -> f64 {
+        let player1 = Rating::from_iter(team1);
+        let player2 = Rating::from_iter(team2);
+        let n = team1.len() + team2.len();
+        let dmu = player1.mean() - player2.mean();
+        let c2 = (n as f64) * self.beta * self.beta + player1.variance() + player2.variance();
+        cdf(dmu / c2.sqrt())
+    }
+
     #[inline]
     fn vw(t: f64, epsilon: f64) -> [f64; 2] {
         let x = t - epsilon;
@@ -141,10 +259,12 @@ mod test {
     static BETA: f64 = 0.5;
     static TAU: f64 = 0.1;
     static DRAW_PROBABILITY: f64 = 0.1;
+    static CONVERGENCE_TOLERANCE: f64 = 1e-4;
     static TRUESKILL: TrueSkill = TrueSkill::new(MU, SIGMA, BETA, TAU, DRAW_PROBABILITY);
 
     static TEAM1: [Rating; 2] = [Rating::new(1.0, 0.1), Rating::new(4.0, 0.5)];
     static TEAM2: [Rating; 2] = [Rating::new(2.0, 0.3), Rating::new(2.5, 0.7)];
+    static TEAM3: [Rating; 2] = [Rating::new(1.5, 0.2), Rating::new(3.0, 0.4)];
 
     #[test]
     fn new() {
@@ -207,12 +327,118 @@ mod test {
         assert_almost_eq!(team2[1].variance(), 0.5740189356703336, 1e-15);
     }
 
+    #[test]
+    fn update_multi_matches_update_for_two_teams() {
+        let mut multi_team1 = TEAM1;
+        let mut multi_team2 = TEAM2;
+        TRUESKILL.update_multi(
+            &mut [&mut multi_team1, &mut multi_team2],
+            &[0, 1],
+            CONVERGENCE_TOLERANCE,
+            1,
+        );
+        let mut team1 = TEAM1;
+        let mut team2 = TEAM2;
+        TRUESKILL.update(&mut team1, &mut team2, Score::Win);
+        assert_almost_eq!(multi_team1[0].mean(), team1[0].mean(), 1e-12);
+        assert_almost_eq!(multi_team1[1].mean(), team1[1].mean(), 1e-12);
+        assert_almost_eq!(multi_team2[0].mean(), team2[0].mean(), 1e-12);
+        assert_almost_eq!(multi_team2[1].mean(), team2[1].mean(), 1e-12);
+        assert_almost_eq!(multi_team1[0].variance(), team1[0].variance(), 1e-12);
+        assert_almost_eq!(multi_team1[1].variance(), team1[1].variance(), 1e-12);
+        assert_almost_eq!(multi_team2[0].variance(), team2[0].variance(), 1e-12);
+        assert_almost_eq!(multi_team2[1].variance(), team2[1].variance(), 1e-12);
+    }
+
+    #[test]
+    fn update_multi_ranked() {
+        let mut team1 = TEAM1;
+        let mut team2 = TEAM2;
+        let mut team3 = TEAM3;
+        TRUESKILL.update_multi(
+            &mut [&mut team1, &mut team2, &mut team3],
+            &[0, 1, 2],
+            CONVERGENCE_TOLERANCE,
+            1,
+        );
+        assert_almost_eq!(team1[0].mean(), 1.0444948551408717, 1e-15);
+        assert_almost_eq!(team1[1].mean(), 4.206294328380405, 1e-15);
+        assert_almost_eq!(team2[0].mean(), 2.034064187347516, 1e-15);
+        assert_almost_eq!(team2[1].mean(), 2.578017977473343, 1e-15);
+        assert_almost_eq!(team3[0].mean(), 1.360547761678625, 1e-15);
+        assert_almost_eq!(team3[1].mean(), 2.7277361061344583, 1e-15);
+        assert_almost_eq!(team1[0].variance(), 0.10732620185993816, 1e-15);
+        assert_almost_eq!(team1[1].variance(), 0.45252438874131495, 1e-15);
+        assert_almost_eq!(team2[0].variance(), 0.2718886517393759, 1e-15);
+        assert_almost_eq!(team2[1].variance(), 0.5100839681771008, 1e-15);
+        assert_almost_eq!(team3[0].variance(), 0.19709332102811486, 1e-15);
+        assert_almost_eq!(team3[1].variance(), 0.360802432308982, 1e-15);
+    }
+
+    #[test]
+    fn update_multi_tied() {
+        let mut team1 = TEAM1;
+        let mut team2 = TEAM2;
+        let mut team3 = TEAM3;
+        TRUESKILL.update_multi(
+            &mut [&mut team1, &mut team2, &mut team3],
+            &[0, 0, 1],
+            CONVERGENCE_TOLERANCE,
+            1,
+        );
+        assert_almost_eq!(team1[0].mean(), 0.9792081691641249, 1e-15);
+        assert_almost_eq!(team1[1].mean(), 3.9036015115791245, 1e-15);
+        assert_almost_eq!(team2[0].mean(), 2.156387537290209, 1e-15);
+        assert_almost_eq!(team2[1].mean(), 2.8581779079872525, 1e-15);
+        assert_almost_eq!(team3[0].mean(), 1.3921780538476933, 1e-15);
+        assert_almost_eq!(team3[1].mean(), 2.7894904860835914, 1e-15);
+        assert_almost_eq!(team1[0].variance(), 0.10542579652761795, 1e-15);
+        assert_almost_eq!(team1[1].variance(), 0.4116735270110269, 1e-15);
+        assert_almost_eq!(team2[0].variance(), 0.2635510617367076, 1e-15);
+        assert_almost_eq!(team2[1].variance(), 0.46634849345967017, 1e-15);
+        assert_almost_eq!(team3[0].variance(), 0.1976978223350113, 1e-15);
+        assert_almost_eq!(team3[1].variance(), 0.363106665181755, 1e-15);
+    }
+
+    #[test]
+    fn update_multi_converges_with_more_iterations() {
+        let mut team1 = TEAM1;
+        let mut team2 = TEAM2;
+        let mut team3 = TEAM3;
+        TRUESKILL.update_multi(
+            &mut [&mut team1, &mut team2, &mut team3],
+            &[0, 1, 2],
+            CONVERGENCE_TOLERANCE,
+            10,
+        );
+        assert_almost_eq!(team1[0].mean(), 1.0615443701643383, 1e-12);
+        assert_almost_eq!(team1[1].mean(), 4.285342079852841, 1e-12);
+        assert_almost_eq!(team2[0].mean(), 2.0326044853811074, 1e-12);
+        assert_almost_eq!(team2[1].mean(), 2.574674789098665, 1e-12);
+        assert_almost_eq!(team3[0].mean(), 1.3604192930149048, 1e-12);
+        assert_almost_eq!(team3[1].mean(), 2.727485286362433, 1e-12);
+        assert_almost_eq!(team1[0].variance(), 0.10662549585285098, 1e-12);
+        assert_almost_eq!(team1[1].variance(), 0.4374621050683089, 1e-12);
+        assert_almost_eq!(team2[0].variance(), 0.2697117941098392, 1e-12);
+        assert_almost_eq!(team2[1].variance(), 0.4986650927239327, 1e-12);
+        assert_almost_eq!(team3[0].variance(), 0.19691703090613574, 1e-12);
+        assert_almost_eq!(team3[1].variance(), 0.3601304511410752, 1e-12);
+    }
+
     #[test]
     fn quality() {
         let quality = TRUESKILL.quality(&TEAM1, &TEAM2);
         assert_almost_eq!(quality, 0.5910630134064284, 1e-15);
     }
 
+    #[test]
+    fn win_probability() {
+        let win_probability = TRUESKILL.win_probability(&TEAM1, &TEAM2);
+        assert_almost_eq!(win_probability, 0.6217525388931737, 1e-15);
+        let win_probability = TRUESKILL.win_probability(&TEAM2, &TEAM1);
+        assert_almost_eq!(win_probability, 0.3782474611068263, 1e-15);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serialize() {
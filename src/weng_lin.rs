@@ -0,0 +1,262 @@
+use core::iter::FromIterator;
+
+use super::{Rating, Score};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WengLin {
+    mu: f64,
+    sigma: f64,
+    beta: f64,
+    tau: f64,
+    kappa: f64,
+}
+
+impl Eq for WengLin {}
+
+impl WengLin {
+    #[inline]
+    pub const fn new(mu: f64, sigma: f64, beta: f64, tau: f64, kappa: f64) -> Self {
+        Self {
+            mu,
+            sigma,
+            beta,
+            tau,
+            kappa,
+        }
+    }
+
+    #[inline]
+    pub const fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    #[inline]
+    pub const fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    #[inline]
+    pub const fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    #[inline]
+    pub const fn tau(&self) -> f64 {
+        self.tau
+    }
+
+    #[inline]
+    pub const fn kappa(&self) -> f64 {
+        self.kappa
+    }
+
+    #[inline]
+    pub fn update(&self, team1: &mut [Rating], team2: &mut [Rating], score: Score) {
+        if score == Score::Loss {
+            self.update(team2, team1, Score::Win);
+            return;
+        }
+        let ranks = if score == Score::Draw { [0, 0] } else { [0, 1] };
+        self.update_multi(&mut [team1, team2], &ranks);
+    }
+
+    #[inline]
+    pub fn update_multi(&self, teams: &mut [&mut [Rating]], ranks: &[usize]) {
+        assert_eq!(teams.len(), ranks.len());
+        let add_dynamic_factor = |x: &mut Rating| *x += Rating::new(0.0, self.tau * self.tau);
+        for team in teams.iter_mut() {
+            team.iter_mut().for_each(add_dynamic_factor);
+        }
+        let team_ratings = teams
+            .iter()
+            .map(|team| Rating::from_iter(team.iter()))
+            .collect::<Vec<_>>();
+        let c2 = team_ratings
+            .iter()
+            .zip(teams.iter())
+            .map(|(rating, team)| rating.variance() + team.len() as f64 * self.beta * self.beta)
+            .sum::<f64>();
+        let c = c2.sqrt();
+
+        let mut omega = vec![0.0; teams.len()];
+        let mut delta = vec![0.0; teams.len()];
+        for q in 0..teams.len() {
+            for r in 0..teams.len() {
+                if q == r {
+                    continue;
+                }
+                let p_qr = (team_ratings[q].mean() / c).exp()
+                    / ((team_ratings[q].mean() / c).exp() + (team_ratings[r].mean() / c).exp());
+                let s = if ranks[q] < ranks[r] {
+                    1.0
+                } else if ranks[q] == ranks[r] {
+                    0.5
+                } else {
+                    0.0
+                };
+                let sigma2_q = team_ratings[q].variance();
+                omega[q] += sigma2_q / c * (s - p_qr);
+                delta[q] += sigma2_q / c2 * (sigma2_q.sqrt() / c) * p_qr * (1.0 - p_qr);
+            }
+        }
+
+        for ((team, team_variance), (&omega, &delta)) in teams
+            .iter_mut()
+            .zip(team_ratings.iter().map(Rating::variance))
+            .zip(omega.iter().zip(&delta))
+        {
+            for x in team.iter_mut() {
+                let ratio = x.variance() / team_variance;
+                *x = Rating::new(
+                    x.mean() + ratio * omega,
+                    x.variance() * (1.0 - ratio * delta).max(self.kappa),
+                );
+            }
+        }
+    }
+
+    #[inline]
+    pub fn create_rating(&self) -> Rating {
+        Rating::new(self.mu, self.sigma * self.sigma)
+    }
+
+    #[inline]
+    pub fn quality(&self, team1: &[Rating], team2: &[Rating]) -> f64 {
+        let rating1 = Rating::from_iter(team1);
+        let rating2 = Rating::from_iter(team2);
+        let n = (team1.len() + team2.len()) as f64;
+        let c2 = rating1.variance() + rating2.variance() + n * self.beta * self.beta;
+        let c = c2.sqrt();
+        let p = 1.0 / (1.0 + ((rating2.mean() - rating1.mean()) / c).exp());
+        2.0 * p.min(1.0 - p)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use statrs::assert_almost_eq;
+
+    use super::*;
+
+    static MU: f64 = 3.0;
+    static SIGMA: f64 = 1.0;
+    static BETA: f64 = 0.5;
+    static TAU: f64 = 0.1;
+    static KAPPA: f64 = 1e-4;
+    static WENG_LIN: WengLin = WengLin::new(MU, SIGMA, BETA, TAU, KAPPA);
+
+    static TEAM1: [Rating; 2] = [Rating::new(1.0, 0.1), Rating::new(4.0, 0.5)];
+    static TEAM2: [Rating; 2] = [Rating::new(2.0, 0.3), Rating::new(2.5, 0.7)];
+    static TEAM3: [Rating; 2] = [Rating::new(1.5, 0.2), Rating::new(3.0, 0.4)];
+
+    #[test]
+    fn new() {
+        assert_almost_eq!(WENG_LIN.mu(), MU, 1e-15);
+        assert_almost_eq!(WENG_LIN.sigma(), SIGMA, 1e-15);
+        assert_almost_eq!(WENG_LIN.beta(), BETA, 1e-15);
+        assert_almost_eq!(WENG_LIN.tau(), TAU, 1e-15);
+        assert_almost_eq!(WENG_LIN.kappa(), KAPPA, 1e-15);
+    }
+
+    #[test]
+    fn create_rating() {
+        let rating = WENG_LIN.create_rating();
+        assert_almost_eq!(rating.mean(), MU, 1e-15);
+        assert_almost_eq!(rating.variance(), SIGMA * SIGMA, 1e-15);
+    }
+
+    #[test]
+    fn update_two_teams() {
+        let mut team1 = TEAM1;
+        let mut team2 = TEAM2;
+        WENG_LIN.update(&mut team1, &mut team2, Score::Win);
+        assert_almost_eq!(team1[0].mean(), 1.028682542280383, 1e-15);
+        assert_almost_eq!(team1[1].mean(), 4.13298269602723, 1e-15);
+        assert_almost_eq!(team2[0].mean(), 1.9191673808461938, 1e-15);
+        assert_almost_eq!(team2[1].mean(), 2.314867227099347, 1e-15);
+        assert_almost_eq!(team1[0].variance(), 0.10945765698083619, 1e-15);
+        assert_almost_eq!(team1[1].variance(), 0.49834186617483384, 1e-15);
+        assert_almost_eq!(team2[0].variance(), 0.3044752031557588, 1e-15);
+        assert_almost_eq!(team2[1].variance(), 0.6810192498524249, 1e-15);
+    }
+
+    #[test]
+    fn update_draw() {
+        let mut team1 = TEAM1;
+        let mut team2 = TEAM2;
+        WENG_LIN.update(&mut team1, &mut team2, Score::Draw);
+        assert_almost_eq!(team1[0].mean(), 0.9948323822610663, 1e-15);
+        assert_almost_eq!(team1[1].mean(), 3.9760410450285804, 1e-15);
+        assert_almost_eq!(team2[0].mean(), 2.014563286355177, 1e-15);
+        assert_almost_eq!(team2[1].mean(), 2.5333546235876625, 1e-15);
+        assert_almost_eq!(team1[0].variance(), 0.10945765698083619, 1e-15);
+        assert_almost_eq!(team1[1].variance(), 0.49834186617483384, 1e-15);
+        assert_almost_eq!(team2[0].variance(), 0.3044752031557588, 1e-15);
+        assert_almost_eq!(team2[1].variance(), 0.6810192498524249, 1e-15);
+    }
+
+    #[test]
+    fn update_loss() {
+        let mut team1 = TEAM1;
+        let mut team2 = TEAM2;
+        WENG_LIN.update(&mut team1, &mut team2, Score::Loss);
+        assert_almost_eq!(team1[0].mean(), 0.9609822222417498, 1e-15);
+        assert_almost_eq!(team1[1].mean(), 3.819099394029931, 1e-15);
+        assert_almost_eq!(team2[0].mean(), 2.1099591918641596, 1e-15);
+        assert_almost_eq!(team2[1].mean(), 2.751842020075978, 1e-15);
+        assert_almost_eq!(team1[0].variance(), 0.10945765698083619, 1e-15);
+        assert_almost_eq!(team1[1].variance(), 0.49834186617483384, 1e-15);
+        assert_almost_eq!(team2[0].variance(), 0.3044752031557588, 1e-15);
+        assert_almost_eq!(team2[1].variance(), 0.6810192498524249, 1e-15);
+    }
+
+    #[test]
+    fn update_multi_ranked() {
+        let mut team1 = TEAM1;
+        let mut team2 = TEAM2;
+        let mut team3 = TEAM3;
+        WENG_LIN.update_multi(&mut [&mut team1, &mut team2, &mut team3], &[0, 1, 2]);
+        assert_almost_eq!(team1[0].mean(), 1.0494545954369214, 1e-15);
+        assert_almost_eq!(team1[1].mean(), 4.2292894879348175, 1e-15);
+        assert_almost_eq!(team2[0].mean(), 2.010249125742122, 1e-15);
+        assert_almost_eq!(team2[1].mean(), 2.523473804119053, 1e-15);
+        assert_almost_eq!(team3[0].mean(), 1.3986437252907158, 1e-15);
+        assert_almost_eq!(team3[1].mean(), 2.802113939853302, 1e-15);
+        assert_almost_eq!(team1[0].variance(), 0.10935735612523736, 1e-15);
+        assert_almost_eq!(team1[1].variance(), 0.49618581224580455, 1e-15);
+        assert_almost_eq!(team2[0].variance(), 0.30339873621349905, 1e-15);
+        assert_almost_eq!(team2[1].variance(), 0.6753725590554098, 1e-15);
+        assert_almost_eq!(team3[0].variance(), 0.2076382275722552, 1e-15);
+        assert_almost_eq!(team3[1].variance(), 0.40099741621079577, 1e-15);
+    }
+
+    #[test]
+    fn quality() {
+        let quality = WENG_LIN.quality(&TEAM1, &TEAM2);
+        assert_almost_eq!(quality, 0.8461870864228664, 1e-15);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let text = serde_json::to_string(&WENG_LIN).unwrap();
+        assert_eq!(
+            text,
+            r#"{"mu":3.0,"sigma":1.0,"beta":0.5,"tau":0.1,"kappa":0.0001}"#
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        let weng_lin = serde_json::from_str::<WengLin>(
+            r#"{"mu":3.0,"sigma":1.0,"beta":0.5,"tau":0.1,"kappa":0.0001}"#,
+        )
+        .unwrap();
+        assert_eq!(weng_lin, WENG_LIN);
+    }
+}